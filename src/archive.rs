@@ -0,0 +1,51 @@
+use domination::DominationOrd;
+
+/// An incremental Pareto archive for the streaming use case where
+/// solutions arrive one at a time and only the current non-dominated
+/// front is kept live.
+///
+/// This mirrors the dominance-checker pattern used in branch-and-bound
+/// solvers to discard dominated states on the fly, and avoids re-running
+/// the full `O(N^2)` sort each time a single candidate is considered.
+pub struct ParetoArchive<S, D> {
+    domination: D,
+    solutions: Vec<S>,
+}
+
+impl<S, D> ParetoArchive<S, D>
+where
+    D: DominationOrd<Solution = S>,
+{
+    pub fn new(domination: D) -> Self {
+        ParetoArchive {
+            domination,
+            solutions: Vec::new(),
+        }
+    }
+
+    /// Returns true if any archived solution dominates `solution`.
+    pub fn contains_dominating(&self, solution: &S) -> bool {
+        self.solutions
+            .iter()
+            .any(|s| self.domination.dominates(s, solution))
+    }
+
+    /// Inserts `solution` into the archive, if it is not dominated by any
+    /// archived solution. Any archived solutions that `solution` dominates
+    /// are removed. Returns true if `solution` was inserted.
+    pub fn insert(&mut self, solution: S) -> bool {
+        if self.contains_dominating(&solution) {
+            return false;
+        }
+
+        let domination = &self.domination;
+        self.solutions.retain(|s| !domination.dominates(&solution, s));
+        self.solutions.push(solution);
+        true
+    }
+
+    /// Returns the current non-dominated front held by the archive.
+    pub fn frontier(&self) -> &[S] {
+        &self.solutions[..]
+    }
+}
@@ -0,0 +1,58 @@
+use domination::DominationOrd;
+use std::cmp::Ordering;
+
+/// Exposes the total constraint violation of a solution, `0.0` meaning
+/// the solution is feasible.
+pub trait ConstraintViolation {
+    /// The sum of all violated constraint magnitudes. Must be `0.0` for a
+    /// feasible solution and positive otherwise.
+    fn total_violation(&self) -> f64;
+}
+
+/// Wraps an inner `DominationOrd` with Deb's constrained-domination rule:
+///
+/// - If one solution is feasible and the other is not, the feasible one
+///   dominates.
+/// - If both are infeasible, the one with the smaller total constraint
+///   violation dominates.
+/// - If both are feasible, the inner `DominationOrd` decides.
+///
+/// This lets `non_dominated_sort` rank mixed feasible/infeasible
+/// populations correctly, which is the standard NSGA-II constraint
+/// handling.
+pub struct ConstrainedDominance<D> {
+    inner: D,
+}
+
+impl<D> ConstrainedDominance<D> {
+    pub fn new(inner: D) -> Self {
+        ConstrainedDominance { inner }
+    }
+}
+
+impl<D> DominationOrd for ConstrainedDominance<D>
+where
+    D: DominationOrd,
+    D::Solution: ConstraintViolation,
+{
+    type Solution = D::Solution;
+
+    fn domination_ord(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        let violation_a = a.total_violation();
+        let violation_b = b.total_violation();
+
+        match (violation_a == 0.0, violation_b == 0.0) {
+            (true, true) => self.inner.domination_ord(a, b),
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (false, false) => violation_a
+                .partial_cmp(&violation_b)
+                .unwrap_or(Ordering::Equal),
+        }
+    }
+}
+
+/// Alias for `ConstrainedDominance`, matching the crate's `*DominationOrd`
+/// naming convention used by `ParetoDominationOrd` and
+/// `EpsilonDominanceOrd`.
+pub type ConstrainedDominationOrd<D> = ConstrainedDominance<D>;
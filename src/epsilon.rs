@@ -0,0 +1,126 @@
+use domination::DominationOrd;
+use objectives::Objectives;
+use std::cmp::Ordering;
+use std::marker::PhantomData;
+
+/// Maps a solution's objective vector onto its epsilon-box coordinates:
+/// `floor(f_i / epsilon_i)` for each objective `i`.
+fn box_coords<S: Objectives>(solution: &S, epsilon: &[f64]) -> Vec<i64> {
+    (0..solution.num_objectives())
+        .map(|i| (solution.objective(i) / epsilon[i]).floor() as i64)
+        .collect()
+}
+
+/// Epsilon-dominance: `a` epsilon-dominates `b` iff `a`'s box is
+/// component-wise no larger than `b`'s box, and strictly smaller in at
+/// least one component. Solutions that fall into the same box are broken
+/// by their true distance to the box's ideal corner.
+///
+/// This is the box-dominance relation used by epsilon-MOEA style
+/// algorithms to maintain a diverse, bounded-size archive of non-dominated
+/// solutions.
+pub struct EpsilonDominanceOrd<S> {
+    epsilon: Vec<f64>,
+    _marker: PhantomData<S>,
+}
+
+impl<S> EpsilonDominanceOrd<S> {
+    pub fn new(epsilon: Vec<f64>) -> Self {
+        EpsilonDominanceOrd {
+            epsilon,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<S> DominationOrd for EpsilonDominanceOrd<S>
+where
+    S: Objectives,
+{
+    type Solution = S;
+
+    fn domination_ord(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        let box_a = box_coords(a, &self.epsilon);
+        let box_b = box_coords(b, &self.epsilon);
+
+        if box_a == box_b {
+            return corner_distance(a, &self.epsilon, &box_a)
+                .partial_cmp(&corner_distance(b, &self.epsilon, &box_b))
+                .unwrap_or(Ordering::Equal);
+        }
+
+        let a_le_b = box_a.iter().zip(box_b.iter()).all(|(x, y)| x <= y);
+        let b_le_a = box_a.iter().zip(box_b.iter()).all(|(x, y)| y <= x);
+
+        if a_le_b && !b_le_a {
+            Ordering::Less
+        } else if b_le_a && !a_le_b {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+}
+
+/// The squared Euclidean distance from `solution` to the ideal corner of
+/// its epsilon-box, used to break ties between solutions sharing a box.
+fn corner_distance<S: Objectives>(solution: &S, epsilon: &[f64], box_: &[i64]) -> f64 {
+    (0..solution.num_objectives())
+        .map(|i| {
+            let corner = box_[i] as f64 * epsilon[i];
+            let d = solution.objective(i) - corner;
+            d * d
+        })
+        .sum()
+}
+
+/// A bounded-size, epsilon-dominance archive: at most one solution is kept
+/// per occupied box, and any solution epsilon-dominated by another is
+/// discarded. This gives a streaming archive whose size is bounded by the
+/// grid resolution defined by `epsilon`, suitable as the archiving
+/// component of a steady-state multi-objective optimizer.
+pub struct EpsilonArchive<S> {
+    epsilon: Vec<f64>,
+    entries: Vec<S>,
+}
+
+impl<S> EpsilonArchive<S>
+where
+    S: Objectives,
+{
+    pub fn new(epsilon: Vec<f64>) -> Self {
+        EpsilonArchive {
+            epsilon,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Inserts `solution`, discarding it if an archived entry epsilon-
+    /// dominates it (including sharing its box with a closer-to-ideal
+    /// entry) or shares its box at the same distance from the ideal corner,
+    /// and removing any archived entries it epsilon-dominates.
+    /// Returns true if `solution` was inserted.
+    pub fn insert(&mut self, solution: S) -> bool {
+        let domination = EpsilonDominanceOrd::new(self.epsilon.clone());
+        let epsilon = &self.epsilon;
+        let solution_box = box_coords(&solution, epsilon);
+
+        if self.entries.iter().any(|s| {
+            domination.dominates(s, &solution)
+                || (box_coords(s, epsilon) == solution_box
+                    && !domination.dominates(&solution, s))
+        }) {
+            return false;
+        }
+
+        self.entries
+            .retain(|s| !domination.dominates(&solution, s));
+        self.entries.push(solution);
+        true
+    }
+
+    /// Returns the solutions currently held by the archive.
+    pub fn entries(&self) -> &[S] {
+        &self.entries[..]
+    }
+}
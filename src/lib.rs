@@ -1,5 +1,23 @@
+pub mod archive;
+pub mod constraint;
 pub mod domination;
+pub mod ens;
+pub mod epsilon;
+pub mod multi_objective;
 pub mod non_dominated_sort_impl;
+pub mod objectives;
+pub mod selection;
 
+pub use archive::ParetoArchive;
+pub use constraint::{ConstrainedDominance, ConstrainedDominationOrd, ConstraintViolation};
 pub use domination::DominationOrd;
-pub use non_dominated_sort_impl::{non_dominated_sort, Front, NonDominatedSort, SolutionWithIndex};
+pub use ens::{
+    non_dominated_sort_ens, non_dominated_sort_ens_with_strategy, EnsStrategy, NonDominatedSortEns,
+};
+pub use epsilon::{EpsilonArchive, EpsilonDominanceOrd};
+pub use multi_objective::{MultiObjective, ObjectiveDirection, ParetoDominationOrd};
+pub use non_dominated_sort_impl::{
+    crowding_distance, non_dominated_sort, Front, NonDominatedSort, RankIndex, SolutionWithIndex,
+};
+pub use objectives::Objectives;
+pub use selection::select_and_rank;
@@ -0,0 +1,78 @@
+use domination::DominationOrd;
+use objectives::Objectives;
+use std::cmp::Ordering;
+use std::marker::PhantomData;
+
+/// Whether an objective is to be minimized or maximized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ObjectiveDirection {
+    Minimize,
+    Maximize,
+}
+
+/// A multi-objective solution that, in addition to exposing its raw
+/// objective values through `Objectives`, knows whether each objective is
+/// to be minimized or maximized.
+///
+/// Implementing this trait is enough to obtain Pareto dominance through
+/// `ParetoDominationOrd`, instead of hand-writing a `DominationOrd` like
+/// `TupleDominationOrd`.
+pub trait MultiObjective: Objectives {
+    /// The direction of the `i`-th objective (`0 <= i < num_objectives()`).
+    fn direction(&self, i: usize) -> ObjectiveDirection;
+}
+
+/// A `DominationOrd` that derives Pareto dominance generically from a
+/// solution's `MultiObjective` implementation: `a` dominates `b` iff `a` is
+/// no worse in every objective and strictly better in at least one,
+/// respecting each objective's direction.
+pub struct ParetoDominationOrd<S> {
+    _marker: PhantomData<S>,
+}
+
+impl<S> ParetoDominationOrd<S> {
+    pub fn new() -> Self {
+        ParetoDominationOrd {
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<S> Default for ParetoDominationOrd<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S> DominationOrd for ParetoDominationOrd<S>
+where
+    S: MultiObjective,
+{
+    type Solution = S;
+
+    fn domination_ord(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        debug_assert_eq!(a.num_objectives(), b.num_objectives());
+
+        let mut a_better = false;
+        let mut b_better = false;
+
+        for i in 0..a.num_objectives() {
+            let (va, vb) = match a.direction(i) {
+                ObjectiveDirection::Minimize => (a.objective(i), b.objective(i)),
+                ObjectiveDirection::Maximize => (b.objective(i), a.objective(i)),
+            };
+
+            if va < vb {
+                a_better = true;
+            } else if va > vb {
+                b_better = true;
+            }
+        }
+
+        match (a_better, b_better) {
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            _ => Ordering::Equal,
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use domination::DominationOrd;
+use objectives::Objectives;
 use std::cmp::Ordering;
 
 pub struct SolutionWithIndex {
@@ -18,6 +19,66 @@ impl Front {
     pub fn solutions_indices_only(&self) -> Vec<usize> {
         self.solutions.iter().map(|s| s.index).collect()
     }
+
+    /// Computes the NSGA-II crowding distance for every solution in this
+    /// front, as a density estimator for diversity-preserving selection.
+    ///
+    /// The returned vector is aligned with `self.solutions`: entry `i` is
+    /// the crowding distance of `self.solutions[i]`. The two boundary
+    /// solutions of each objective (lowest and highest value) receive an
+    /// infinite distance, so they rank ahead of every interior solution
+    /// when a front is truncated by crowding distance.
+    pub fn crowding_distance<S>(&self, solutions: &[S]) -> Vec<f64>
+    where
+        S: Objectives,
+    {
+        let n = self.solutions.len();
+        let mut distance = vec![0.0; n];
+        if n == 0 {
+            return distance;
+        }
+
+        let num_objectives = solutions[self.solutions[0].index].num_objectives();
+
+        for m in 0..num_objectives {
+            let mut order: Vec<usize> = (0..n).collect();
+            order.sort_by(|&a, &b| {
+                let fa = solutions[self.solutions[a].index].objective(m);
+                let fb = solutions[self.solutions[b].index].objective(m);
+                fa.partial_cmp(&fb).unwrap_or(Ordering::Equal)
+            });
+
+            distance[order[0]] = f64::INFINITY;
+            distance[order[n - 1]] = f64::INFINITY;
+
+            let f_min = solutions[self.solutions[order[0]].index].objective(m);
+            let f_max = solutions[self.solutions[order[n - 1]].index].objective(m);
+            let range = f_max - f_min;
+            if range == 0.0 {
+                continue;
+            }
+
+            for w in 1..n - 1 {
+                let prev = solutions[self.solutions[order[w - 1]].index].objective(m);
+                let next = solutions[self.solutions[order[w + 1]].index].objective(m);
+                distance[order[w]] += (next - prev) / range;
+            }
+        }
+
+        distance
+    }
+}
+
+/// Computes the NSGA-II crowding distance of every solution in `front`.
+///
+/// This is a free-function counterpart to `Front::crowding_distance`, kept
+/// for symmetry with `non_dominated_sort`, which is also exposed both as a
+/// function and through the `NonDominatedSort` type.
+pub fn crowding_distance<S>(front: &Front, solutions: &[S]) -> Vec<f64>
+where
+    S: Objectives,
+{
+    front.crowding_distance(solutions)
 }
 
 struct Entry<'a, S>
@@ -334,6 +395,55 @@ impl<'a, S> NonDominatedSort<'a, S> {
         }
         return fronts;
     }
+
+    /// Builds a `RankIndex` from this completed sort in a single linear
+    /// pass over the final domination counts, instead of materializing all
+    /// fronts via repeated calls to `next()`.
+    pub fn into_rank_index(self) -> RankIndex {
+        let rank = self
+            .dominations
+            .iter()
+            .map(|d| (-d.domination_count) as usize)
+            .collect();
+
+        RankIndex { rank }
+    }
+}
+
+/// A precomputed rank/dominance query index produced from a completed
+/// `NonDominatedSort`, allowing O(1) lookups of the front rank a solution
+/// belongs to, suitable for selection loops that query ranks many times.
+pub struct RankIndex {
+    /// The front number of solution `i` is `rank[i]`.
+    rank: Vec<usize>,
+}
+
+impl RankIndex {
+    /// The front (rank) solution `i` belongs to, `0` being the first
+    /// front.
+    pub fn rank_of(&self, i: usize) -> usize {
+        self.rank[i]
+    }
+
+    /// Compares the ranks of solutions `a` and `b`.
+    pub fn compare_ranks(&self, a: usize, b: usize) -> Ordering {
+        self.rank[a].cmp(&self.rank[b])
+    }
+
+    /// Groups all solution indices by their front rank, ordered from the
+    /// first front onward.
+    pub fn fronts_grouped(&self) -> Vec<Vec<usize>> {
+        let mut fronts: Vec<Vec<usize>> = Vec::new();
+
+        for (i, &r) in self.rank.iter().enumerate() {
+            if r >= fronts.len() {
+                fronts.resize(r + 1, Vec::new());
+            }
+            fronts[r].push(i);
+        }
+
+        fronts
+    }
 }
 
 /// Iterate over the pareto fronts. Each call to next() will yield the
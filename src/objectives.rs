@@ -0,0 +1,13 @@
+/// Provides access to the individual objective values of a multi-objective
+/// solution.
+///
+/// This decouples features that need the raw objective vector -- such as
+/// crowding-distance estimation -- from the opaque pairwise comparisons
+/// that `DominationOrd` performs.
+pub trait Objectives {
+    /// The number of objectives this solution is evaluated on.
+    fn num_objectives(&self) -> usize;
+
+    /// The value of the `i`-th objective (`0 <= i < num_objectives()`).
+    fn objective(&self, i: usize) -> f64;
+}
@@ -0,0 +1,46 @@
+use domination::DominationOrd;
+use non_dominated_sort_impl::NonDominatedSort;
+use objectives::Objectives;
+use std::cmp::Ordering;
+
+/// Selects `n` solutions out of `solutions` (all of them, if there are
+/// fewer than `n`), ordered by (rank ascending, crowding distance
+/// descending).
+///
+/// Whole fronts are taken while they still fit within `n`; the front that
+/// would overflow it is truncated by crowding distance, keeping the most
+/// diverse solutions. This is the standard survivor-selection step used by
+/// NSGA-II to fill a fixed-size population, building on top of
+/// `pareto_fronts` and `Front::crowding_distance`.
+pub fn select_and_rank<S, D>(solutions: &[S], n: usize, domination: &D) -> Vec<usize>
+where
+    S: Objectives,
+    D: DominationOrd<Solution = S>,
+{
+    let mut selected = Vec::with_capacity(n.min(solutions.len()));
+
+    for front in NonDominatedSort::new(solutions, domination) {
+        let remaining = n - selected.len();
+        let crowding = front.crowding_distance(solutions);
+
+        let mut order: Vec<usize> = (0..front.solutions.len()).collect();
+        order.sort_by(|&a, &b| {
+            crowding[b]
+                .partial_cmp(&crowding[a])
+                .unwrap_or(Ordering::Equal)
+        });
+
+        selected.extend(
+            order
+                .into_iter()
+                .take(remaining)
+                .map(|i| front.solutions[i].index),
+        );
+
+        if selected.len() >= n {
+            break;
+        }
+    }
+
+    selected
+}
@@ -0,0 +1,58 @@
+extern crate non_dominated_sort;
+
+use non_dominated_sort::{DominationOrd, ParetoArchive};
+use std::cmp::Ordering;
+
+struct Tuple(usize, usize);
+
+struct TupleDominationOrd;
+
+impl DominationOrd for TupleDominationOrd {
+    type Solution = Tuple;
+
+    fn domination_ord(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        if a.0 < b.0 && a.1 <= b.1 {
+            Ordering::Less
+        } else if a.0 <= b.0 && a.1 < b.1 {
+            Ordering::Less
+        } else if a.0 > b.0 && a.1 >= b.1 {
+            Ordering::Greater
+        } else if a.0 >= b.0 && a.1 > b.1 {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+}
+
+#[test]
+fn test_archive_rejects_dominated_solution() {
+    let mut archive = ParetoArchive::new(TupleDominationOrd);
+
+    assert!(archive.insert(Tuple(1, 1)));
+    assert!(!archive.insert(Tuple(2, 2)));
+    assert_eq!(1, archive.frontier().len());
+}
+
+#[test]
+fn test_archive_prunes_dominated_entries() {
+    let mut archive = ParetoArchive::new(TupleDominationOrd);
+
+    assert!(archive.insert(Tuple(2, 2)));
+    assert!(archive.insert(Tuple(3, 1)));
+    assert_eq!(2, archive.frontier().len());
+
+    // Dominates both existing entries.
+    assert!(archive.insert(Tuple(1, 1)));
+    assert_eq!(1, archive.frontier().len());
+    assert_eq!((1, 1), (archive.frontier()[0].0, archive.frontier()[0].1));
+}
+
+#[test]
+fn test_contains_dominating() {
+    let mut archive = ParetoArchive::new(TupleDominationOrd);
+    archive.insert(Tuple(1, 1));
+
+    assert!(archive.contains_dominating(&Tuple(2, 2)));
+    assert!(!archive.contains_dominating(&Tuple(0, 0)));
+}
@@ -0,0 +1,100 @@
+extern crate non_dominated_sort;
+
+use non_dominated_sort::{
+    ConstrainedDominance, ConstrainedDominationOrd, ConstraintViolation, DominationOrd,
+};
+use std::cmp::Ordering;
+
+struct Solution {
+    value: f64,
+    violation: f64,
+}
+
+impl ConstraintViolation for Solution {
+    fn total_violation(&self) -> f64 {
+        self.violation
+    }
+}
+
+struct ValueDominationOrd;
+
+impl DominationOrd for ValueDominationOrd {
+    type Solution = Solution;
+
+    fn domination_ord(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        a.value.partial_cmp(&b.value).unwrap_or(Ordering::Equal)
+    }
+}
+
+#[test]
+fn test_feasible_dominates_infeasible() {
+    let domination = ConstrainedDominance::new(ValueDominationOrd);
+
+    let feasible = Solution {
+        value: 10.0,
+        violation: 0.0,
+    };
+    let infeasible = Solution {
+        value: 1.0,
+        violation: 0.1,
+    };
+
+    assert_eq!(
+        Ordering::Less,
+        domination.domination_ord(&feasible, &infeasible)
+    );
+}
+
+#[test]
+fn test_smaller_violation_dominates_when_both_infeasible() {
+    let domination = ConstrainedDominance::new(ValueDominationOrd);
+
+    let less_infeasible = Solution {
+        value: 10.0,
+        violation: 0.1,
+    };
+    let more_infeasible = Solution {
+        value: 1.0,
+        violation: 5.0,
+    };
+
+    assert_eq!(
+        Ordering::Less,
+        domination.domination_ord(&less_infeasible, &more_infeasible)
+    );
+}
+
+#[test]
+fn test_falls_back_to_inner_when_both_feasible() {
+    let domination = ConstrainedDominance::new(ValueDominationOrd);
+
+    let a = Solution {
+        value: 1.0,
+        violation: 0.0,
+    };
+    let b = Solution {
+        value: 2.0,
+        violation: 0.0,
+    };
+
+    assert_eq!(Ordering::Less, domination.domination_ord(&a, &b));
+}
+
+#[test]
+fn test_constrained_domination_ord_alias() {
+    let domination = ConstrainedDominationOrd::new(ValueDominationOrd);
+
+    let feasible = Solution {
+        value: 10.0,
+        violation: 0.0,
+    };
+    let infeasible = Solution {
+        value: 1.0,
+        violation: 0.1,
+    };
+
+    assert_eq!(
+        Ordering::Less,
+        domination.domination_ord(&feasible, &infeasible)
+    );
+}
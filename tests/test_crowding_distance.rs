@@ -0,0 +1,62 @@
+extern crate non_dominated_sort;
+
+use non_dominated_sort::{crowding_distance, DominationOrd, NonDominatedSort, Objectives};
+use std::cmp::Ordering;
+
+struct Tuple(f64, f64);
+
+impl Objectives for Tuple {
+    fn num_objectives(&self) -> usize {
+        2
+    }
+
+    fn objective(&self, i: usize) -> f64 {
+        match i {
+            0 => self.0,
+            1 => self.1,
+            _ => unreachable!(),
+        }
+    }
+}
+
+struct TupleDominationOrd;
+
+impl DominationOrd for TupleDominationOrd {
+    type Solution = Tuple;
+
+    fn domination_ord(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        if a.0 < b.0 && a.1 <= b.1 {
+            Ordering::Less
+        } else if a.0 <= b.0 && a.1 < b.1 {
+            Ordering::Less
+        } else if a.0 > b.0 && a.1 >= b.1 {
+            Ordering::Greater
+        } else if a.0 >= b.0 && a.1 > b.1 {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+}
+
+#[test]
+fn test_crowding_distance_boundary_solutions_are_infinite() {
+    let solutions = vec![
+        Tuple(0.0, 3.0),
+        Tuple(1.0, 2.0),
+        Tuple(2.0, 1.0),
+        Tuple(3.0, 0.0),
+    ];
+
+    let fronts = NonDominatedSort::new(&solutions, &TupleDominationOrd).pareto_fronts();
+    assert_eq!(1, fronts.len());
+
+    let front = &fronts[0];
+    let indices = front.solutions_indices_only();
+    let distances = crowding_distance(front, &solutions);
+
+    let boundary_a = indices.iter().position(|&i| i == 0).unwrap();
+    let boundary_b = indices.iter().position(|&i| i == 3).unwrap();
+    assert_eq!(f64::INFINITY, distances[boundary_a]);
+    assert_eq!(f64::INFINITY, distances[boundary_b]);
+}
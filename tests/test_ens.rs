@@ -0,0 +1,79 @@
+extern crate non_dominated_sort;
+
+use non_dominated_sort::{non_dominated_sort, non_dominated_sort_ens, DominationOrd, Objectives};
+use std::cmp::Ordering;
+
+struct Tuple(f64, f64);
+
+impl Objectives for Tuple {
+    fn num_objectives(&self) -> usize {
+        2
+    }
+
+    fn objective(&self, i: usize) -> f64 {
+        match i {
+            0 => self.0,
+            1 => self.1,
+            _ => unreachable!(),
+        }
+    }
+}
+
+struct TupleDominationOrd;
+
+impl DominationOrd for TupleDominationOrd {
+    type Solution = Tuple;
+
+    fn domination_ord(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        if a.0 < b.0 && a.1 <= b.1 {
+            Ordering::Less
+        } else if a.0 <= b.0 && a.1 < b.1 {
+            Ordering::Less
+        } else if a.0 > b.0 && a.1 >= b.1 {
+            Ordering::Greater
+        } else if a.0 >= b.0 && a.1 > b.1 {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+}
+
+fn get_solutions() -> Vec<Tuple> {
+    vec![
+        Tuple(1.0, 2.0),
+        Tuple(1.0, 2.0),
+        Tuple(2.0, 1.0),
+        Tuple(1.0, 3.0),
+        Tuple(0.0, 2.0),
+    ]
+}
+
+fn sorted(mut v: Vec<usize>) -> Vec<usize> {
+    v.sort();
+    v
+}
+
+#[test]
+fn test_ens_matches_plain_sort() {
+    let solutions = get_solutions();
+
+    let mut plain = non_dominated_sort(&solutions, &TupleDominationOrd);
+    let mut ens = non_dominated_sort_ens(&solutions, &TupleDominationOrd);
+
+    loop {
+        assert_eq!(plain.rank(), ens.rank());
+        assert_eq!(
+            sorted(plain.current_front_indices().to_vec()),
+            sorted(ens.current_front_indices().to_vec())
+        );
+
+        if plain.is_empty() {
+            assert!(ens.is_empty());
+            break;
+        }
+
+        plain = plain.next_front();
+        ens = ens.next_front();
+    }
+}
@@ -0,0 +1,90 @@
+extern crate non_dominated_sort;
+
+use non_dominated_sort::{
+    non_dominated_sort_ens_with_strategy, DominationOrd, EnsStrategy, Objectives,
+};
+use std::cmp::Ordering;
+
+struct Tuple(f64, f64);
+
+impl Objectives for Tuple {
+    fn num_objectives(&self) -> usize {
+        2
+    }
+
+    fn objective(&self, i: usize) -> f64 {
+        match i {
+            0 => self.0,
+            1 => self.1,
+            _ => unreachable!(),
+        }
+    }
+}
+
+struct TupleDominationOrd;
+
+impl DominationOrd for TupleDominationOrd {
+    type Solution = Tuple;
+
+    fn domination_ord(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        if a.0 < b.0 && a.1 <= b.1 {
+            Ordering::Less
+        } else if a.0 <= b.0 && a.1 < b.1 {
+            Ordering::Less
+        } else if a.0 > b.0 && a.1 >= b.1 {
+            Ordering::Greater
+        } else if a.0 >= b.0 && a.1 > b.1 {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+}
+
+// Deterministic pseudo-random generator, avoiding an extra dependency.
+fn lcg(seed: &mut u64) -> u64 {
+    *seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1);
+    *seed
+}
+
+fn random_solutions(count: usize, seed: u64) -> Vec<Tuple> {
+    let mut state = seed;
+    (0..count)
+        .map(|_| {
+            let a = (lcg(&mut state) % 20) as f64;
+            let b = (lcg(&mut state) % 20) as f64;
+            Tuple(a, b)
+        })
+        .collect()
+}
+
+fn sorted(mut v: Vec<usize>) -> Vec<usize> {
+    v.sort();
+    v
+}
+
+#[test]
+fn test_ens_ss_and_bs_agree_on_random_inputs() {
+    let solutions = random_solutions(200, 42);
+
+    let mut ss =
+        non_dominated_sort_ens_with_strategy(&solutions, &TupleDominationOrd, EnsStrategy::SequentialScan);
+    let mut bs =
+        non_dominated_sort_ens_with_strategy(&solutions, &TupleDominationOrd, EnsStrategy::BinarySearch);
+
+    loop {
+        assert_eq!(ss.rank(), bs.rank());
+        assert_eq!(
+            sorted(ss.current_front_indices().to_vec()),
+            sorted(bs.current_front_indices().to_vec())
+        );
+
+        if ss.is_empty() {
+            assert!(bs.is_empty());
+            break;
+        }
+
+        ss = ss.next_front();
+        bs = bs.next_front();
+    }
+}
@@ -0,0 +1,49 @@
+extern crate non_dominated_sort;
+
+use non_dominated_sort::{EpsilonArchive, Objectives};
+
+struct Point(f64, f64);
+
+impl Objectives for Point {
+    fn num_objectives(&self) -> usize {
+        2
+    }
+
+    fn objective(&self, i: usize) -> f64 {
+        match i {
+            0 => self.0,
+            1 => self.1,
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[test]
+fn test_epsilon_archive_keeps_one_per_box() {
+    let mut archive = EpsilonArchive::new(vec![1.0, 1.0]);
+
+    // Both fall into box (0, 0); the closer one to the ideal corner wins.
+    assert!(archive.insert(Point(0.5, 0.5)));
+    assert!(archive.insert(Point(0.1, 0.1)));
+    assert_eq!(1, archive.entries().len());
+    assert_eq!(0.1, archive.entries()[0].0);
+}
+
+#[test]
+fn test_epsilon_archive_discards_dominated_boxes() {
+    let mut archive = EpsilonArchive::new(vec![1.0, 1.0]);
+
+    assert!(archive.insert(Point(2.0, 2.0)));
+    // Box (0, 0) epsilon-dominates box (2, 2).
+    assert!(archive.insert(Point(0.0, 0.0)));
+    assert_eq!(1, archive.entries().len());
+}
+
+#[test]
+fn test_epsilon_archive_keeps_non_dominated_boxes() {
+    let mut archive = EpsilonArchive::new(vec![1.0, 1.0]);
+
+    assert!(archive.insert(Point(0.0, 5.0)));
+    assert!(archive.insert(Point(5.0, 0.0)));
+    assert_eq!(2, archive.entries().len());
+}
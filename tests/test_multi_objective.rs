@@ -0,0 +1,76 @@
+extern crate non_dominated_sort;
+
+use non_dominated_sort::{
+    non_dominated_sort, MultiObjective, ObjectiveDirection, Objectives, ParetoDominationOrd,
+};
+
+// One objective to minimize (cost), one to maximize (score).
+struct Candidate {
+    cost: f64,
+    score: f64,
+}
+
+impl Objectives for Candidate {
+    fn num_objectives(&self) -> usize {
+        2
+    }
+
+    fn objective(&self, i: usize) -> f64 {
+        match i {
+            0 => self.cost,
+            1 => self.score,
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl MultiObjective for Candidate {
+    fn direction(&self, i: usize) -> ObjectiveDirection {
+        match i {
+            0 => ObjectiveDirection::Minimize,
+            1 => ObjectiveDirection::Maximize,
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[test]
+fn test_pareto_domination_ord_respects_directions() {
+    let domination = ParetoDominationOrd::new();
+
+    let cheaper_and_better = Candidate {
+        cost: 1.0,
+        score: 9.0,
+    };
+    let pricier_and_worse = Candidate {
+        cost: 2.0,
+        score: 5.0,
+    };
+
+    let solutions = vec![cheaper_and_better, pricier_and_worse];
+    let f0 = non_dominated_sort(&solutions, &domination);
+    assert_eq!(&[0], f0.current_front_indices());
+
+    let f1 = f0.next_front();
+    assert_eq!(&[1], f1.current_front_indices());
+}
+
+#[test]
+fn test_pareto_domination_ord_non_domination() {
+    let domination = ParetoDominationOrd::new();
+
+    let cheap_but_poor = Candidate {
+        cost: 1.0,
+        score: 1.0,
+    };
+    let pricier_but_better = Candidate {
+        cost: 2.0,
+        score: 9.0,
+    };
+
+    let solutions = vec![cheap_but_poor, pricier_but_better];
+    let f0 = non_dominated_sort(&solutions, &domination);
+    let mut front = f0.current_front_indices().to_vec();
+    front.sort();
+    assert_eq!(vec![0, 1], front);
+}
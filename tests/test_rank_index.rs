@@ -0,0 +1,58 @@
+extern crate non_dominated_sort;
+
+use non_dominated_sort::{DominationOrd, NonDominatedSort};
+use std::cmp::Ordering;
+
+struct Tuple(usize, usize);
+
+struct TupleDominationOrd;
+
+impl DominationOrd for TupleDominationOrd {
+    type Solution = Tuple;
+
+    fn domination_ord(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        if a.0 < b.0 && a.1 <= b.1 {
+            Ordering::Less
+        } else if a.0 <= b.0 && a.1 < b.1 {
+            Ordering::Less
+        } else if a.0 > b.0 && a.1 >= b.1 {
+            Ordering::Greater
+        } else if a.0 >= b.0 && a.1 > b.1 {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+}
+
+fn get_solutions() -> Vec<Tuple> {
+    vec![
+        Tuple(1, 2),
+        Tuple(1, 2),
+        Tuple(2, 1),
+        Tuple(1, 3),
+        Tuple(0, 2),
+    ]
+}
+
+#[test]
+fn test_rank_index_matches_pareto_fronts() {
+    let solutions = get_solutions();
+    let rank_index = NonDominatedSort::new(&solutions, &TupleDominationOrd).into_rank_index();
+
+    assert_eq!(0, rank_index.rank_of(2));
+    assert_eq!(0, rank_index.rank_of(4));
+    assert_eq!(1, rank_index.rank_of(0));
+    assert_eq!(1, rank_index.rank_of(1));
+    assert_eq!(2, rank_index.rank_of(3));
+
+    assert_eq!(Ordering::Less, rank_index.compare_ranks(2, 0));
+    assert_eq!(Ordering::Equal, rank_index.compare_ranks(0, 1));
+    assert_eq!(Ordering::Greater, rank_index.compare_ranks(3, 0));
+
+    let mut grouped = rank_index.fronts_grouped();
+    for front in grouped.iter_mut() {
+        front.sort();
+    }
+    assert_eq!(vec![vec![2, 4], vec![0, 1], vec![3]], grouped);
+}
@@ -0,0 +1,68 @@
+extern crate non_dominated_sort;
+
+use non_dominated_sort::{select_and_rank, DominationOrd, Objectives};
+use std::cmp::Ordering;
+
+// A 2-objective solution, both objectives minimized.
+struct Solution(f64, f64);
+
+impl Objectives for Solution {
+    fn num_objectives(&self) -> usize {
+        2
+    }
+
+    fn objective(&self, i: usize) -> f64 {
+        match i {
+            0 => self.0,
+            1 => self.1,
+            _ => unreachable!(),
+        }
+    }
+}
+
+struct SolutionDominationOrd;
+
+impl DominationOrd for SolutionDominationOrd {
+    type Solution = Solution;
+
+    fn domination_ord(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        if a.0 <= b.0 && a.1 <= b.1 && (a.0 < b.0 || a.1 < b.1) {
+            Ordering::Less
+        } else if b.0 <= a.0 && b.1 <= a.1 && (b.0 < a.0 || b.1 < a.1) {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+}
+
+#[test]
+fn test_select_and_rank_takes_whole_fronts_first() {
+    let solutions = vec![
+        Solution(0.0, 3.0), // front 0
+        Solution(1.0, 1.0), // front 0
+        Solution(3.0, 0.0), // front 0
+        Solution(2.0, 2.0), // front 1
+        Solution(4.0, 4.0), // front 2
+    ];
+
+    let selected = select_and_rank(&solutions, 3, &SolutionDominationOrd);
+    let mut sorted = selected.clone();
+    sorted.sort();
+    assert_eq!(vec![0, 1, 2], sorted);
+}
+
+#[test]
+fn test_select_and_rank_truncates_last_front_by_crowding_distance() {
+    let solutions = vec![
+        Solution(0.0, 3.0), // front 0, boundary -> kept
+        Solution(1.0, 2.0), // front 0, interior, crowding 4/3
+        Solution(2.0, 1.0), // front 0, interior, crowding 4/3
+        Solution(3.0, 0.0), // front 0, boundary -> kept
+    ];
+
+    let selected = select_and_rank(&solutions, 3, &SolutionDominationOrd);
+    assert_eq!(3, selected.len());
+    assert!(selected.contains(&0));
+    assert!(selected.contains(&3));
+}